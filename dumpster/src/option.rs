@@ -6,7 +6,7 @@
     file, You can obtain one at http://mozilla.org/MPL/2.0/.
 */
 
-//! Contains macro to create optional garbage collection pointers.
+//! Contains macros to create optional garbage collection pointers.
 
 /// Macro to create optional garbage collection pointers.
 macro_rules! make_opt_gc {
@@ -37,6 +37,50 @@ macro_rules! make_opt_gc {
             /// ```
             #[expect(clippy::declare_interior_mutable_const)]
             pub const NONE: Self = Self(Gc::<T>::DEAD);
+
+            /// Returns the option if it is some and the predicate returns `true`,
+            /// otherwise returns none.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use dumpster::", stringify!($module), "::{Gc, OptGc};")]
+            ///
+            /// assert!(OptGc::some(Gc::new(4)).filter(|n| **n % 2 == 0).is_some());
+            /// assert!(OptGc::some(Gc::new(3)).filter(|n| **n % 2 == 0).is_none());
+            /// assert!(OptGc::<i32>::NONE.filter(|_| true).is_none());
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn filter<P: FnOnce(&Gc<T>) -> bool>(self, predicate: P) -> Self {
+                let keep = match self.as_ref() {
+                    Some(gc) => predicate(gc),
+                    None => false,
+                };
+
+                if keep {
+                    self
+                } else {
+                    Self::NONE
+                }
+            }
+
+            /// Takes the value out of the option, leaving none in its place.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use dumpster::", stringify!($module), "::{Gc, OptGc};")]
+            ///
+            /// let mut x = OptGc::some(Gc::new(2));
+            /// let y = x.take();
+            /// assert!(x.is_none());
+            /// assert_eq!(*y.unwrap(), 2);
+            /// ```
+            #[inline]
+            pub fn take(&mut self) -> Option<Gc<T>> {
+                ::core::mem::replace(self, Self::NONE).into_option()
+            }
         }
 
         impl<T: Trace $(+ $($bounds)*)? + ?Sized + 'static> OptGc<T> {
@@ -220,6 +264,183 @@ macro_rules! make_opt_gc {
                     None => 0,
                 }
             }
+
+            /// Maps an `OptGc<T>` to an `Option<U>` by applying a function to the
+            /// contained `Gc<T>`, or returns `None` if there is no value.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use dumpster::", stringify!($module), "::{Gc, OptGc};")]
+            ///
+            /// let text: OptGc<str> = OptGc::some(Gc::from("Hello, world!"));
+            /// let len: Option<usize> = text.map(|s| s.len());
+            /// assert_eq!(len, Some(13));
+            /// ```
+            #[inline]
+            pub fn map<U, F: FnOnce(Gc<T>) -> U>(self, f: F) -> Option<U> {
+                if self.0.is_dead() {
+                    None
+                } else {
+                    Some(f(self.0))
+                }
+            }
+
+            /// Returns `None` if the option is none, otherwise calls `f` with the
+            /// contained `Gc<T>` and returns the result.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use dumpster::", stringify!($module), "::{Gc, OptGc};")]
+            ///
+            /// let x: OptGc<i32> = OptGc::some(Gc::new(2));
+            /// assert_eq!(x.and_then(|n| if *n > 1 { Some(*n) } else { None }), Some(2));
+            /// ```
+            #[inline]
+            pub fn and_then<U, F: FnOnce(Gc<T>) -> Option<U>>(self, f: F) -> Option<U> {
+                if self.0.is_dead() {
+                    None
+                } else {
+                    f(self.0)
+                }
+            }
+
+            /// Returns the option if it contains a value, otherwise returns `other`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use dumpster::", stringify!($module), "::{Gc, OptGc};")]
+            ///
+            /// let x = OptGc::<i32>::NONE.or(OptGc::some(Gc::new(2)));
+            /// assert_eq!(**x.as_ref().unwrap(), 2);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn or(self, other: Self) -> Self {
+                if self.0.is_dead() {
+                    other
+                } else {
+                    self
+                }
+            }
+
+            /// Returns the option if it contains a value, otherwise calls `f` and
+            /// returns the result.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use dumpster::", stringify!($module), "::{Gc, OptGc};")]
+            ///
+            /// let x = OptGc::some(Gc::new(1)).or_else(|| OptGc::some(Gc::new(2)));
+            /// assert_eq!(**x.as_ref().unwrap(), 1);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn or_else<F: FnOnce() -> Self>(self, f: F) -> Self {
+                if self.0.is_dead() {
+                    f()
+                } else {
+                    self
+                }
+            }
+
+            /// Returns the contained `Gc<T>` or a provided default.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use dumpster::", stringify!($module), "::{Gc, OptGc};")]
+            ///
+            /// assert_eq!(*OptGc::some(Gc::new(5)).unwrap_or(Gc::new(0)), 5);
+            /// assert_eq!(*OptGc::<i32>::NONE.unwrap_or(Gc::new(0)), 0);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn unwrap_or(self, default: Gc<T>) -> Gc<T> {
+                if self.0.is_dead() {
+                    default
+                } else {
+                    self.0
+                }
+            }
+
+            /// Returns the contained `Gc<T>` or computes it from a closure.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use dumpster::", stringify!($module), "::{Gc, OptGc};")]
+            ///
+            /// assert_eq!(*OptGc::<i32>::NONE.unwrap_or_else(|| Gc::new(9)), 9);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn unwrap_or_else<F: FnOnce() -> Gc<T>>(self, f: F) -> Gc<T> {
+                if self.0.is_dead() {
+                    f()
+                } else {
+                    self.0
+                }
+            }
+
+            /// Inserts `gc` into the option if it is none, then returns a mutable
+            /// reference to the contained `Gc<T>`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use dumpster::", stringify!($module), "::{Gc, OptGc};")]
+            ///
+            /// let mut x = OptGc::<i32>::NONE;
+            /// let v = x.get_or_insert(Gc::new(7));
+            /// assert_eq!(**v, 7);
+            /// ```
+            #[inline]
+            pub fn get_or_insert(&mut self, gc: Gc<T>) -> &mut Gc<T> {
+                self.get_or_insert_with(|| gc)
+            }
+
+            /// Inserts a value computed from `f` into the option if it is none, then
+            /// returns a mutable reference to the contained `Gc<T>`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use dumpster::", stringify!($module), "::{Gc, OptGc};")]
+            ///
+            /// let mut x = OptGc::<i32>::NONE;
+            /// let v = x.get_or_insert_with(|| Gc::new(7));
+            /// assert_eq!(**v, 7);
+            /// ```
+            #[inline]
+            pub fn get_or_insert_with<F: FnOnce() -> Gc<T>>(&mut self, f: F) -> &mut Gc<T> {
+                if self.0.is_dead() {
+                    self.0 = f();
+                }
+
+                &mut self.0
+            }
+
+            /// Replaces the contained value with `gc`, returning the old value if
+            /// there was one.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use dumpster::", stringify!($module), "::{Gc, OptGc};")]
+            ///
+            /// let mut x = OptGc::some(Gc::new(2));
+            /// let old = x.replace(Gc::new(5));
+            /// assert_eq!(**x.as_ref().unwrap(), 5);
+            /// assert_eq!(*old.unwrap(), 2);
+            /// ```
+            #[inline]
+            pub fn replace(&mut self, gc: Gc<T>) -> Option<Gc<T>> {
+                ::core::mem::replace(self, Self::some(gc)).into_option()
+            }
         }
 
         impl<T: Trace $(+ $($bounds)*)? + ?Sized + 'static + fmt::Debug> fmt::Debug for OptGc<T> {
@@ -276,3 +497,132 @@ macro_rules! make_opt_gc {
 }
 
 pub(crate) use make_opt_gc;
+
+/// Macro to create optional weak garbage collection pointers.
+macro_rules! make_opt_weak {
+    ($module:ident, $visit:ident; $($($bounds:tt)+)?) => {
+        /// An alternative to <code>[Option]\<[Weak]\<T\>\></code> that takes up less space.
+        ///
+        /// Specifically `OptWeak<T>` always has the same size as `Weak<T>`.
+        ///
+        /// # Interaction with `Drop`
+        ///
+        /// This is implemented by interpreting a dead `Weak` as none.
+        /// So during a `Drop` implementation this type can turn into none.
+        pub struct OptWeak<T: Trace $(+ $($bounds)*)? + ?Sized + 'static>(Weak<T>);
+
+        impl<T: Trace $(+ $($bounds)*)? + 'static> OptWeak<T> {
+            /// An `OptWeak<T>` representing no value.
+            ///
+            /// This is only available for `Sized` values.
+            #[expect(clippy::declare_interior_mutable_const)]
+            pub const NONE: Self = Self(Weak::<T>::DEAD);
+
+            /// Upgrade this weak pointer to a strong one.
+            ///
+            /// Returns [`OptGc::NONE`] if this is none or if the pointed-to value has
+            /// already been dropped.
+            #[inline]
+            pub fn upgrade(&self) -> OptGc<T> {
+                match self.as_ref() {
+                    Some(weak) => weak.upgrade().into(),
+                    None => OptGc::NONE,
+                }
+            }
+        }
+
+        impl<T: Trace $(+ $($bounds)*)? + ?Sized + 'static> OptWeak<T> {
+            /// Returns `true` if the option is some value of `T`.
+            #[inline]
+            pub fn is_some(&self) -> bool {
+                !self.0.is_dead()
+            }
+
+            /// Returns `true` if the option is no value.
+            #[inline]
+            pub fn is_none(&self) -> bool {
+                self.0.is_dead()
+            }
+
+            /// Create an `OptWeak<T>` from a `Weak<T>`.
+            #[inline]
+            #[must_use]
+            pub fn some(weak: Weak<T>) -> Self {
+                Self(weak)
+            }
+
+            /// Converts from `&OptWeak<T>` to `Option<&Weak<T>>`.
+            #[inline]
+            pub fn as_ref(&self) -> Option<&Weak<T>> {
+                if self.0.is_dead() {
+                    return None;
+                }
+
+                Some(&self.0)
+            }
+
+            /// Convert this `OptWeak<T>` into an `Option<Weak<T>>`.
+            #[inline]
+            pub fn into_option(self) -> Option<Weak<T>> {
+                if self.0.is_dead() {
+                    return None;
+                }
+
+                Some(self.0)
+            }
+
+            /// Determine whether two `OptWeak`s are equivalent by reference.
+            /// Returns `true` if both point to the same value, in the same style as
+            /// [`std::ptr::eq`].
+            #[inline]
+            pub fn ptr_eq(&self, other: &Self) -> bool {
+                Weak::ptr_eq(&self.0, &other.0)
+            }
+        }
+
+        impl<T: Trace $(+ $($bounds)*)? + ?Sized + 'static + fmt::Debug> fmt::Debug for OptWeak<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.as_ref(), f)
+            }
+        }
+
+        unsafe impl<V: Visitor, T: Trace $(+ $($bounds)*)? + ?Sized> TraceWith<V> for OptWeak<T> {
+            fn accept(&self, visitor: &mut V) -> Result<(), ()> {
+                if let Some(weak) = self.as_ref() {
+                    visitor.$visit(weak);
+                }
+
+                Ok(())
+            }
+        }
+
+        impl<T: Trace $(+ $($bounds)*)? + 'static> Clone for OptWeak<T> {
+            fn clone(&self) -> Self {
+                Weak::try_clone(&self.0).into()
+            }
+        }
+
+        impl<T: Trace $(+ $($bounds)*)? + 'static> Default for OptWeak<T> {
+            fn default() -> Self {
+                Self::NONE
+            }
+        }
+
+        impl<T: Trace $(+ $($bounds)*)? + 'static> From<Option<Weak<T>>> for OptWeak<T> {
+            fn from(value: Option<Weak<T>>) -> Self {
+                match value {
+                    Some(weak) => Self(weak),
+                    None => Self::NONE,
+                }
+            }
+        }
+
+        impl<T: Trace $(+ $($bounds)*)? + 'static> From<OptWeak<T>> for Option<Weak<T>> {
+            fn from(value: OptWeak<T>) -> Self {
+                value.into_option()
+            }
+        }
+    };
+}
+
+pub(crate) use make_opt_weak;
@@ -13,7 +13,7 @@
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{parse_quote, Path, Result};
+use syn::{parse_quote, punctuated::Punctuated, LitStr, Path, Result, Token, WherePredicate};
 
 synstructure::decl_derive!(
     [Trace, attributes(dumpster)] =>
@@ -24,6 +24,7 @@ synstructure::decl_derive!(
 fn derive_trace(mut s: synstructure::Structure) -> Result<TokenStream> {
     let mut dumpster: Path = parse_quote!(::dumpster);
     let mut trace_ignore_container = false;
+    let mut container_bound: Option<Vec<WherePredicate>> = None;
 
     // look for container attributes
     for attr in &s.ast().attrs {
@@ -35,6 +36,9 @@ fn derive_trace(mut s: synstructure::Structure) -> Result<TokenStream> {
             if meta.path.is_ident("crate") {
                 dumpster = meta.value()?.parse()?;
                 Ok(())
+            } else if meta.path.is_ident("bound") {
+                container_bound = Some(parse_bound(&meta.value()?.parse()?)?);
+                Ok(())
             } else if meta.path.is_ident("trace") {
                 meta.parse_nested_meta(|meta| {
                     if meta.path.is_ident("ignore") {
@@ -55,53 +59,75 @@ fn derive_trace(mut s: synstructure::Structure) -> Result<TokenStream> {
         s.add_bounds(synstructure::AddBounds::None);
         quote!()
     } else {
-        // Every field must implement `Trace` (but not necessarily the generics).
-        s.add_bounds(synstructure::AddBounds::Fields);
-
         // There is no `try_filter` so we store the parse error here, to return it
         // after the `filter` call.
         let mut field_attr_parse_error = None;
 
-        // Filter out fields with `#[dumpster(trace(ignore))]`
-        s.filter(|bi| {
-            let mut trace_ignore = false;
-
-            for attr in &bi.ast().attrs {
-                if !attr.path().is_ident("dumpster") {
-                    continue;
-                }
-
-                let result = attr.parse_nested_meta(|meta| {
-                    if meta.path.is_ident("trace") {
-                        meta.parse_nested_meta(|meta| {
-                            if meta.path.is_ident("ignore") {
-                                trace_ignore = true;
-                                Ok(())
-                            } else {
-                                Err(meta.error("unsupported trace attribute argument"))
-                            }
-                        })
-                    } else {
-                        Err(meta.error("unsupported attribute"))
-                    }
-                });
+        // Set while filtering so we know whether the automatic field bounds
+        // have to be replaced with something hand-written.
+        let mut needs_custom_bounds = false;
 
-                if let Err(error) = result {
-                    field_attr_parse_error.get_or_insert(error);
+        // Filter out fields with `#[dumpster(trace(ignore))]`
+        s.filter(|bi| match parse_field_attrs(bi) {
+            Ok(field) => {
+                // A `with` function is given a field that need not implement
+                // `Trace`, and an explicit `bound` replaces the inferred one;
+                // either way the blanket field bounds no longer fit.
+                if field.with.is_some() || field.bound.is_some() {
+                    needs_custom_bounds = true;
                 }
+                !field.ignore
+            }
+            Err(error) => {
+                field_attr_parse_error.get_or_insert(error);
+                true
             }
-
-            !trace_ignore
         });
 
         if let Some(error) = field_attr_parse_error {
             return Err(error);
         }
 
-        let body = s.each(|bi| {
-            quote! {
-                #dumpster::TraceWith::accept(#bi, visitor)?;
+        if let Some(predicates) = container_bound {
+            // A container `bound` takes over completely: no field bounds are
+            // inferred, the supplied predicates are spliced in verbatim.
+            s.add_bounds(synstructure::AddBounds::None);
+            for predicate in predicates {
+                s.add_where_predicate(predicate);
             }
+        } else if needs_custom_bounds {
+            // Build the where-clause field by field: an explicit `bound` wins,
+            // a `with` field contributes nothing, everything else keeps the
+            // inferred `Trace` bound.
+            let mut predicates = Vec::new();
+            for variant in s.variants() {
+                for bi in variant.bindings() {
+                    let field = parse_field_attrs(bi)?;
+                    if let Some(bound) = field.bound {
+                        predicates.extend(bound);
+                    } else if field.with.is_none() {
+                        let ty = &bi.ast().ty;
+                        predicates.push(parse_quote!(#ty: #dumpster::TraceWith<__V>));
+                    }
+                }
+            }
+
+            s.add_bounds(synstructure::AddBounds::None);
+            for predicate in predicates {
+                s.add_where_predicate(predicate);
+            }
+        } else {
+            // Every field must implement `Trace` (but not necessarily the generics).
+            s.add_bounds(synstructure::AddBounds::Fields);
+        }
+
+        let body = s.each(|bi| match parse_field_attrs(bi) {
+            Ok(FieldAttrs { with: Some(with), .. }) => quote! {
+                #with(#bi, visitor)?;
+            },
+            _ => quote! {
+                #dumpster::TraceWith::accept(#bi, visitor)?;
+            },
         });
 
         quote!(match *self { #body })
@@ -117,3 +143,59 @@ fn derive_trace(mut s: synstructure::Structure) -> Result<TokenStream> {
         }
     }))
 }
+
+/// The `#[dumpster(...)]` attributes understood on a field.
+#[derive(Default)]
+struct FieldAttrs {
+    /// Set by `trace(ignore)`; the field is not traced at all.
+    ignore: bool,
+    /// Set by `trace(with = "path")`; names a `fn(&FieldTy, &mut V) -> Result<(), ()>`
+    /// to call instead of `TraceWith::accept`.
+    with: Option<Path>,
+    /// Set by `bound = "..."`; the predicates to splice into the `impl`'s
+    /// where-clause in place of this field's inferred bound.
+    bound: Option<Vec<WherePredicate>>,
+}
+
+/// Parse the `#[dumpster(...)]` attributes on a single field.
+fn parse_field_attrs(bi: &synstructure::BindingInfo) -> Result<FieldAttrs> {
+    let mut attrs = FieldAttrs::default();
+
+    for attr in &bi.ast().attrs {
+        if !attr.path().is_ident("dumpster") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bound") {
+                attrs.bound = Some(parse_bound(&meta.value()?.parse()?)?);
+                Ok(())
+            } else if meta.path.is_ident("trace") {
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("ignore") {
+                        attrs.ignore = true;
+                        Ok(())
+                    } else if meta.path.is_ident("with") {
+                        let lit: LitStr = meta.value()?.parse()?;
+                        attrs.with = Some(lit.parse()?);
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported trace attribute argument"))
+                    }
+                })
+            } else {
+                Err(meta.error("unsupported attribute"))
+            }
+        })?;
+    }
+
+    Ok(attrs)
+}
+
+/// Parse a `bound = "..."` string into a list of where-clause predicates.
+fn parse_bound(lit: &LitStr) -> Result<Vec<WherePredicate>> {
+    Ok(lit
+        .parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?
+        .into_iter()
+        .collect())
+}